@@ -2,6 +2,39 @@
 // The board is a 2D grid where each cell can be either alive (1) or dead (0).
 // It tracks the current state of all cells and maintains a count of living cells.
 
+pub mod pattern;
+
+use std::collections::{HashMap, HashSet};
+
+// A tiny xorshift64* PRNG, embedded so that randomizing a board from a seed
+// is reproducible without pulling in an external crate
+struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    fn new(seed: u64) -> Self {
+        // A zero state never produces anything but zeroes, so nudge it
+        Self {
+            state: if seed == 0 { 0xdead_beef_cafe_f00d } else { seed },
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // Returns a float in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
 #[derive(Clone)]
 pub struct Board {
     pub size_x: i8,           // Width of the board (number of columns)
@@ -9,6 +42,11 @@ pub struct Board {
     pub board: Vec<Vec<i8>>,  // 2D vector storing cell states (0=dead, 1=alive)
     next_board: Vec<Vec<i8>>, // Pre allocated buffer for next generation
     pub population: u64,      // Total number of living cells
+    pub wrap: bool,           // When true, neighbor counting wraps around the edges
+    birth: [bool; 9],         // birth[n] is true if a dead cell with n neighbors comes alive
+    survive: [bool; 9],       // survive[n] is true if a live cell with n neighbors stays alive
+    pub sparse: bool,         // When true, `tick` only visits cells near existing activity
+    live_cells: HashSet<(i16, i16)>, // Coordinates of every living cell, kept in sync with `board`
 }
 
 impl Board {
@@ -17,15 +55,86 @@ impl Board {
 
     pub fn new(_size_x: i8, _size_y: i8) -> Self {
         // This returns the value of the struct
+        let mut birth = [false; 9];
+        let mut survive = [false; 9];
+
+        // Default to standard Conway rules (B3/S23)
+        birth[3] = true;
+        survive[2] = true;
+        survive[3] = true;
+
         Self {
             size_x: _size_x,
             size_y: _size_y,
             board: Self::make_board(_size_x, _size_y),
             next_board: Self::make_board(_size_x, _size_y),
             population: 0,
+            wrap: false,
+            birth,
+            survive,
+            sparse: false,
+            live_cells: HashSet::new(),
         }
     }
 
+    // Switches between the dense (visits every cell) and sparse (visits
+    // only cells near existing activity) simulation engines
+    pub fn set_sparse(&mut self, sparse: bool) {
+        self.sparse = sparse;
+    }
+
+    // Iterates over the coordinates of every living cell, for renderers
+    // that want to draw directly from the sparse live-cell set
+    pub fn live_cells(&self) -> impl Iterator<Item = &(i16, i16)> {
+        self.live_cells.iter()
+    }
+
+    // Parses a Life rulestring like "B3/S23" (HighLife is "B36/S23", Day &
+    // Night is "B3678/S34678", etc.) and replaces the birth/survive tables
+    // used by `tick`.
+    pub fn set_rule(&mut self, rule: &str) -> Result<(), String> {
+        let (birth_part, survive_part) = rule
+            .split_once('/')
+            .ok_or_else(|| format!("rule '{}' is missing the '/' separator", rule))?;
+
+        let birth_digits = birth_part
+            .strip_prefix(['B', 'b'])
+            .ok_or_else(|| format!("rule '{}' must start with 'B'", rule))?;
+        let survive_digits = survive_part
+            .strip_prefix(['S', 's'])
+            .ok_or_else(|| format!("rule '{}' must have 'S' after the '/'", rule))?;
+
+        let birth = Self::parse_digits(birth_digits)?;
+        let survive = Self::parse_digits(survive_digits)?;
+
+        self.birth = birth;
+        self.survive = survive;
+
+        Ok(())
+    }
+
+    // Turns a run of digit characters (e.g. "3678") into a neighbor-count
+    // lookup table, erroring on anything that isn't a single digit 0-8
+    fn parse_digits(digits: &str) -> Result<[bool; 9], String> {
+        let mut table = [false; 9];
+
+        for digit in digits.chars() {
+            let n = digit
+                .to_digit(10)
+                .filter(|&n| n <= 8)
+                .ok_or_else(|| format!("'{}' is not a valid neighbor count (0-8)", digit))?;
+
+            table[n as usize] = true;
+        }
+
+        Ok(table)
+    }
+
+    // Enables or disables toroidal (wrap-around) neighbor counting
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
     // Creates a 2D vector making an empty board with all dead cells
     // A Vec<Vec<i8>> where all cells are initialized to 0 (dead)
     pub fn make_board(size_x: i8, size_y: i8) -> Vec<Vec<i8>> {
@@ -54,10 +163,47 @@ impl Board {
             // Cell is dead, make it alive
             *cell = 1;
             self.population += 1;
+            self.live_cells.insert((board_x as i16, board_y as i16));
         } else {
             // Cell is alive, make it dead
             *cell = 0;
             self.population -= 1;
+            self.live_cells.remove(&(board_x as i16, board_y as i16));
+        }
+    }
+
+    // Fills the board from a seeded PRNG, setting each cell alive with
+    // probability `density`. Reusing the same seed reproduces the same
+    // board, which makes interesting soups easy to share.
+    pub fn randomize(&mut self, density: f64, seed: u64) {
+        let mut rng = Xorshift64::new(seed);
+        let mut new_population: u64 = 0;
+
+        self.live_cells.clear();
+
+        for y in 0..self.size_y {
+            for x in 0..self.size_x {
+                let alive = rng.next_f64() < density;
+                self.board[y as usize][x as usize] = if alive { 1 } else { 0 };
+
+                if alive {
+                    new_population += 1;
+                    self.live_cells.insert((x as i16, y as i16));
+                }
+            }
+        }
+
+        self.population = new_population;
+    }
+
+    // Sets the cell at the given position alive, if it isn't already
+    pub fn set_cell_alive(&mut self, board_x: i8, board_y: i8) {
+        let cell: &mut i8 = &mut self.board[board_y as usize][board_x as usize];
+
+        if *cell == 0 {
+            *cell = 1;
+            self.population += 1;
+            self.live_cells.insert((board_x as i16, board_y as i16));
         }
     }
 
@@ -69,15 +215,20 @@ impl Board {
             }
         }
         self.population = 0;
+        self.live_cells.clear();
     }
 
-    // Advances the simulation by one generation using Conway's Game of Life rules
-    // Here are the rules:
-    // - Any live cell with 2 or 3 live neighbors survives
-    // - Any dead cell with exactly 3 live neighbors becomes alive
-    // - All other live cells die (underpopulation or overpopulation)
-    // - All other dead cells stay dead
+    // Advances the simulation by one generation according to the current
+    // birth/survive rule (B3/S23 by default, see `set_rule`):
+    // - A dead cell with a neighbor count in `birth` becomes alive
+    // - A live cell with a neighbor count in `survive` stays alive
+    // - Every other cell dies or stays dead
     pub fn tick(&mut self) {
+        if self.sparse {
+            self.tick_sparse();
+            return;
+        }
+
         let mut new_population: u64 = 0;
 
         // Process each cell
@@ -87,17 +238,17 @@ impl Board {
                 let neighbour_count: i8 = self.get_neighbour_count(x, y);
                 let current_state: i8 = self.board[y as usize][x as usize];
 
-                // Apply Conway's Game of Life rules
+                // Apply the current rule's lookup tables
                 self.next_board[y as usize][x as usize] = if current_state == 1 {
                     // Cell is currently alive
-                    if neighbour_count == 2 || neighbour_count == 3 {
+                    if self.survive[neighbour_count as usize] {
                         1 // Survives
                     } else {
                         0 // Dies from underpopulation or overpopulation
                     }
                 } else {
                     // Cell is currently dead
-                    if neighbour_count == 3 {
+                    if self.birth[neighbour_count as usize] {
                         1 // Becomes alive (reproduction)
                     } else {
                         0 // Stays dead
@@ -114,11 +265,96 @@ impl Board {
         // Swap the boards instead of copying
         std::mem::swap(&mut self.board, &mut self.next_board);
         self.population = new_population;
+
+        // Keep the live-cell set in sync so switching to the sparse engine
+        // mid-run starts from an accurate picture of the board
+        self.live_cells.clear();
+        for y in 0..self.size_y {
+            for x in 0..self.size_x {
+                if self.board[y as usize][x as usize] == 1 {
+                    self.live_cells.insert((x as i16, y as i16));
+                }
+            }
+        }
+    }
+
+    // Advances the simulation by only visiting cells near existing activity:
+    // tallies each live cell's neighbors into a map, then the next
+    // generation is every coordinate whose tally satisfies `birth` plus
+    // every currently-live coordinate whose tally satisfies `survive`. This
+    // is cheap on huge, mostly-dead boards where `tick` would otherwise
+    // scan the whole grid for nothing. Dead cells with no live neighbors are
+    // never visited, so a rule that births on 0 neighbors (B0) has no effect
+    // here.
+    fn tick_sparse(&mut self) {
+        let mut tally: HashMap<(i16, i16), u8> = HashMap::new();
+        let size_x = self.size_x as i16;
+        let size_y = self.size_y as i16;
+
+        for &(x, y) in &self.live_cells {
+            for dy in -1..=1i16 {
+                for dx in -1..=1i16 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+
+                    let (nx, ny) = if self.wrap {
+                        (
+                            ((x + dx) % size_x + size_x) % size_x,
+                            ((y + dy) % size_y + size_y) % size_y,
+                        )
+                    } else {
+                        (x + dx, y + dy)
+                    };
+
+                    if nx >= 0 && nx < size_x && ny >= 0 && ny < size_y {
+                        *tally.entry((nx, ny)).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        let mut next_live: HashSet<(i16, i16)> = HashSet::new();
+        for (&cell, &count) in &tally {
+            let was_alive = self.live_cells.contains(&cell);
+            let stays_or_born = if was_alive {
+                self.survive[count as usize]
+            } else {
+                self.birth[count as usize]
+            };
+
+            if stays_or_born {
+                next_live.insert(cell);
+            }
+        }
+
+        // Live cells with no live neighbors never enter the tally
+        if self.survive[0] {
+            for &cell in &self.live_cells {
+                if !tally.contains_key(&cell) {
+                    next_live.insert(cell);
+                }
+            }
+        }
+
+        // Keep the dense view in sync for rendering, touching only the
+        // cells that actually changed rather than the whole board
+        for &(x, y) in self.live_cells.difference(&next_live) {
+            self.board[y as usize][x as usize] = 0;
+        }
+        for &(x, y) in next_live.difference(&self.live_cells) {
+            self.board[y as usize][x as usize] = 1;
+        }
+
+        self.population = next_live.len() as u64;
+        self.live_cells = next_live;
     }
 
     // Counts the number of living neighbors around a given cell
-    // Checks all 8 adjacent cells (including diagonals). Cells outside
-    // the board boundaries are considered dead.
+    // Checks all 8 adjacent cells (including diagonals). In wrap mode the
+    // board is treated as toroidal, so neighbors past one edge re-enter on
+    // the opposite edge. Otherwise cells outside the board boundaries are
+    // considered dead.
     pub fn get_neighbour_count(&self, x: i8, y: i8) -> i8 {
         let mut neighbour_count: i8 = 0;
 
@@ -130,13 +366,23 @@ impl Board {
                     continue;
                 }
 
-                // Calculate neighbor coordinates
-                let nx = x + dx;
-                let ny = y + dy;
+                if self.wrap {
+                    // Use i16 intermediates so the modulo math can't overflow i8
+                    let size_x = self.size_x as i16;
+                    let size_y = self.size_y as i16;
+                    let nx = ((x as i16 + dx as i16 + size_x) % size_x) as usize;
+                    let ny = ((y as i16 + dy as i16 + size_y) % size_y) as usize;
 
-                // Only count neighbors that are within board boundaries
-                if nx >= 0 && nx < self.size_x && ny >= 0 && ny < self.size_y {
-                    neighbour_count += self.board[ny as usize][nx as usize];
+                    neighbour_count += self.board[ny][nx];
+                } else {
+                    // Calculate neighbor coordinates
+                    let nx = x + dx;
+                    let ny = y + dy;
+
+                    // Only count neighbors that are within board boundaries
+                    if nx >= 0 && nx < self.size_x && ny >= 0 && ny < self.size_y {
+                        neighbour_count += self.board[ny as usize][nx as usize];
+                    }
                 }
             }
         }
@@ -0,0 +1,280 @@
+// Import and export of the standard Life pattern formats: run-length
+// encoded (.rle) and plaintext (.cells). Both formats describe a pattern
+// as its own small board, which callers then `stamp` onto the one they're
+// editing.
+
+use super::Board;
+
+// Describes why a pattern file could not be parsed
+#[derive(Debug)]
+pub struct ParseError {
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Board {
+    // Serializes the board to the RLE pattern format: a header describing
+    // the dimensions and rule, followed by run-length-encoded rows of
+    // `b` (dead), `o` (alive) and `$` (end of row), terminated by `!`.
+    pub fn to_rle(&self) -> String {
+        let mut rows = Vec::with_capacity(self.size_y as usize);
+
+        for y in 0..self.size_y as usize {
+            let mut row = String::new();
+            let mut x = 0usize;
+
+            while x < self.size_x as usize {
+                let alive = self.board[y][x] == 1;
+                let mut run = 1;
+
+                while x + run < self.size_x as usize && (self.board[y][x + run] == 1) == alive {
+                    run += 1;
+                }
+
+                if run > 1 {
+                    row.push_str(&run.to_string());
+                }
+                row.push(if alive { 'o' } else { 'b' });
+
+                x += run;
+            }
+
+            rows.push(row);
+        }
+
+        format!(
+            "x = {}, y = {}, rule = {}\n{}!\n",
+            self.size_x,
+            self.size_y,
+            self.rule_string(),
+            rows.join("$")
+        )
+    }
+
+    // Parses an RLE pattern into a freshly sized board, ready to `stamp`
+    // onto an existing one.
+    pub fn from_rle(input: &str) -> Result<Board, ParseError> {
+        let mut size_x: i8 = 0;
+        let mut size_y: i8 = 0;
+        let mut rule: Option<String> = None;
+        let mut body = String::new();
+
+        for line in input.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('x') {
+                for part in line.split(',') {
+                    let part = part.trim();
+
+                    if let Some(value) = part.strip_prefix('x') {
+                        size_x = Self::parse_header_value(value)?;
+                    } else if let Some(value) = part.strip_prefix('y') {
+                        size_y = Self::parse_header_value(value)?;
+                    } else if let Some(value) = part.strip_prefix("rule") {
+                        rule = Some(value.trim().trim_start_matches('=').trim().to_string());
+                    }
+                }
+            } else {
+                body.push_str(line);
+            }
+        }
+
+        if size_x <= 0 || size_y <= 0 {
+            return Err(ParseError {
+                message: format!(
+                    "'x = .., y = ..' header must give positive dimensions, got x={}, y={}",
+                    size_x, size_y
+                ),
+            });
+        }
+
+        let mut board = Board::new(size_x, size_y);
+
+        if let Some(rule) = rule {
+            board.set_rule(&rule).map_err(|message| ParseError { message })?;
+        }
+
+        // x/y/run_count are deliberately wider than the board's own i8
+        // coordinates: a malformed or oversized run in the body (e.g. a
+        // row that runs past the declared width) must not overflow while
+        // we walk past the board's bounds, it should just be skipped below
+        let mut x: usize = 0;
+        let mut y: usize = 0;
+        let mut run_count: u32 = 0;
+
+        for ch in body.chars() {
+            match ch {
+                '!' => break,
+                '0'..='9' => {
+                    let digit = ch.to_digit(10).unwrap();
+                    run_count = run_count
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(digit))
+                        .ok_or_else(|| ParseError {
+                            message: "run-length count is too large".to_string(),
+                        })?;
+                }
+                'b' | 'o' => {
+                    let run = if run_count == 0 { 1 } else { run_count } as usize;
+
+                    for _ in 0..run {
+                        if ch == 'o' && x < size_x as usize && y < size_y as usize {
+                            board.set_cell_alive(x as i8, y as i8);
+                        }
+                        x += 1;
+                    }
+
+                    run_count = 0;
+                }
+                '$' => {
+                    let run = if run_count == 0 { 1 } else { run_count } as usize;
+                    y += run;
+                    x = 0;
+                    run_count = 0;
+                }
+                _ => {
+                    return Err(ParseError {
+                        message: format!("unexpected character '{}' in pattern body", ch),
+                    });
+                }
+            }
+        }
+
+        Ok(board)
+    }
+
+    // Parses a "x = .." / "y = .." header field into an i8, erroring if it
+    // isn't a valid number
+    fn parse_header_value(value: &str) -> Result<i8, ParseError> {
+        value
+            .trim()
+            .trim_start_matches('=')
+            .trim()
+            .parse()
+            .map_err(|_| ParseError {
+                message: format!("invalid header value '{}'", value),
+            })
+    }
+
+    // Serializes the board to the plaintext pattern format: one `.`/`O`
+    // character per dead/alive cell, one row per line.
+    pub fn to_plaintext(&self) -> String {
+        let mut out = String::new();
+
+        for y in 0..self.size_y as usize {
+            for x in 0..self.size_x as usize {
+                out.push(if self.board[y][x] == 1 { 'O' } else { '.' });
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    // Parses a plaintext pattern into a freshly sized board, ready to
+    // `stamp` onto an existing one. Lines starting with `!` are comments.
+    pub fn from_plaintext(input: &str) -> Result<Board, ParseError> {
+        let rows: Vec<&str> = input.lines().filter(|line| !line.starts_with('!')).collect();
+
+        if rows.len() > i8::MAX as usize {
+            return Err(ParseError {
+                message: format!(
+                    "plaintext pattern has {} rows, more than the {} a board supports",
+                    rows.len(),
+                    i8::MAX
+                ),
+            });
+        }
+
+        let max_row_len = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        if max_row_len > i8::MAX as usize {
+            return Err(ParseError {
+                message: format!(
+                    "plaintext pattern row is {} characters wide, more than the {} a board supports",
+                    max_row_len,
+                    i8::MAX
+                ),
+            });
+        }
+
+        let size_y = rows.len() as i8;
+        let size_x = max_row_len as i8;
+
+        if size_x == 0 || size_y == 0 {
+            return Err(ParseError {
+                message: "plaintext pattern is empty".to_string(),
+            });
+        }
+
+        let mut board = Board::new(size_x, size_y);
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                match ch {
+                    'O' => board.set_cell_alive(x as i8, y as i8),
+                    '.' => {}
+                    _ => {
+                        return Err(ParseError {
+                            message: format!("unexpected character '{}' in plaintext pattern", ch),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(board)
+    }
+
+    // Stamps another board's live cells onto this one at the given
+    // top-left offset, skipping any cell that falls outside the board
+    pub fn stamp(&mut self, pattern: &Board, origin_x: i8, origin_y: i8) {
+        for y in 0..pattern.size_y {
+            for x in 0..pattern.size_x {
+                if pattern.board[y as usize][x as usize] == 1 {
+                    let board_x = origin_x as i16 + x as i16;
+                    let board_y = origin_y as i16 + y as i16;
+
+                    if board_x >= 0
+                        && board_x < self.size_x as i16
+                        && board_y >= 0
+                        && board_y < self.size_y as i16
+                    {
+                        self.set_cell_alive(board_x as i8, board_y as i8);
+                    }
+                }
+            }
+        }
+    }
+
+    // Stamps another board's live cells centered on this one
+    pub fn stamp_centered(&mut self, pattern: &Board) {
+        let origin_x = (self.size_x - pattern.size_x) / 2;
+        let origin_y = (self.size_y - pattern.size_y) / 2;
+
+        self.stamp(pattern, origin_x, origin_y);
+    }
+
+    // Builds a Life rulestring (e.g. "B3/S23") from the current birth/survive tables
+    fn rule_string(&self) -> String {
+        let digits = |table: &[bool; 9]| -> String {
+            (0..9)
+                .filter(|&n| table[n])
+                .map(|n| n.to_string())
+                .collect()
+        };
+
+        format!("B{}/S{}", digits(&self.birth), digits(&self.survive))
+    }
+}
@@ -12,19 +12,50 @@ use piston_window;
 use piston_window::graphics::{Context, Graphics, clear, rectangle};
 use piston_window::*;
 
-// Import timing utilities for auto-advance
-use std::time::{Duration, Instant};
+// Import timing utilities for auto-advance and seeding the RNG
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-// Import HashSet for tracking pressed keys
+// Import HashSet for tracking painted cells during a drag
 use std::collections::HashSet;
 
+// Import fs for saving/loading pattern files
+use std::fs;
+
+// Where pattern save/load keys read and write, in the two supported formats
+const RLE_PATH: &str = "pattern.rle";
+const PLAINTEXT_PATH: &str = "pattern.cells";
+
+// Whether the simulation is advancing on its own or waiting for the player
+#[derive(PartialEq)]
+enum SimState {
+    Paused,
+    Running,
+}
+
 fn main() {
     // Configuration constants for the game window
     const CELL_SIZE: f64 = 20.0; // Size of each cell in pixels
     const BOARD_WIDTH: i8 = 50; // Number of cells horizontally
     const BOARD_HEIGHT: i8 = 50; // Number of cells vertically
     const FPS: u8 = 60; // This is the fps of the game
-    const AUTO_TICK_INTERVAL_MS: u64 = 50; // Milliseconds between auto ticks when F is held
+    const DEFAULT_AUTO_TICK_INTERVAL_MS: u64 = 50; // Milliseconds between auto ticks while running
+    const MIN_AUTO_TICK_INTERVAL_MS: u64 = 10; // Fastest the + key can make the simulation run
+    const MAX_AUTO_TICK_INTERVAL_MS: u64 = 1000; // Slowest the - key can make the simulation run
+    const AUTO_TICK_STEP_MS: u64 = 10; // Amount +/- adjusts the interval by
+    const DEFAULT_RANDOM_DENSITY: f64 = 0.3; // Fraction of cells set alive by random seeding
+
+    // Rulestrings to cycle through with the E key (Conway, HighLife, Day & Night, Seeds)
+    const RULES: [&str; 4] = ["B3/S23", "B36/S23", "B3678/S34678", "B2/S"];
+
+    // Optional `seed` and `density` positional CLI args (e.g. `game_of_life
+    // 1234567890 0.4`) let a user re-enter the exact soup the G key printed
+    // on a previous run, instead of always drawing a fresh one
+    let mut cli_args = std::env::args().skip(1);
+    let cli_seed: Option<u64> = cli_args.next().and_then(|arg| arg.parse().ok());
+    let random_density: f64 = cli_args
+        .next()
+        .and_then(|arg| arg.parse().ok())
+        .unwrap_or(DEFAULT_RANDOM_DENSITY);
 
     // Calculate window dimensions based on board size and cell size
     let window_width: u32 = (BOARD_WIDTH as f64 * CELL_SIZE) as u32;
@@ -46,8 +77,22 @@ fn main() {
     let mut board_x: i8 = 0;
     let mut board_y: i8 = 0;
 
-    // Track which keys are currently pressed
-    let mut pressed_keys: HashSet<Key> = HashSet::new();
+    // Whether the simulation is currently auto-advancing, and how fast
+    let mut sim_state: SimState = SimState::Paused;
+    let mut auto_tick_interval_ms: u64 = DEFAULT_AUTO_TICK_INTERVAL_MS;
+
+    // Drag-to-draw state: whether the left mouse button is held, the last
+    // painted board cell (to interpolate from), the cells already painted
+    // during the current stroke (so a cell is set only once), and whether
+    // the cursor has left its starting cell (a plain click that never
+    // leaves its cell toggles instead of only ever painting alive)
+    let mut left_mouse_down: bool = false;
+    let mut last_paint_cell: Option<(i8, i8)> = None;
+    let mut painted_this_stroke: HashSet<(i8, i8)> = HashSet::new();
+    let mut drag_moved: bool = false;
+
+    // Index into RULES of the rule currently applied to the board
+    let mut rule_index: usize = 0;
 
     // Track last auto-tick time for continuous advancement
     let mut last_auto_tick: Instant = Instant::now();
@@ -58,18 +103,99 @@ fn main() {
     while let Some(event) = events.next(&mut window) {
         // Track key press events
         if let Some(Button::Keyboard(key)) = event.press_args() {
-            pressed_keys.insert(key);
-
             // Handle one time press actions
             match key {
                 Key::Right => {
                     board.tick();
                     generation += 1;
                 }
+                Key::Space => {
+                    // Single-step always forces a pause, mirroring standard Life editors
+                    sim_state = SimState::Paused;
+                    board.tick();
+                    generation += 1;
+                }
+                Key::Return => {
+                    sim_state = match sim_state {
+                        SimState::Paused => SimState::Running,
+                        SimState::Running => SimState::Paused,
+                    };
+                }
+                Key::Equals => {
+                    // "+" speeds the simulation up by shrinking the tick interval
+                    let faster = auto_tick_interval_ms.saturating_sub(AUTO_TICK_STEP_MS);
+                    auto_tick_interval_ms = faster.max(MIN_AUTO_TICK_INTERVAL_MS);
+                }
+                Key::Minus => {
+                    // "-" slows the simulation down by growing the tick interval
+                    let slower = auto_tick_interval_ms + AUTO_TICK_STEP_MS;
+                    auto_tick_interval_ms = slower.min(MAX_AUTO_TICK_INTERVAL_MS);
+                }
                 Key::R => {
                     board.clear_board();
                     generation = 0;
                 }
+                Key::W => {
+                    board.set_wrap(!board.wrap);
+                }
+                Key::G => {
+                    // Reuse the seed passed on the command line if there is
+                    // one, so that soup can be reproduced; otherwise draw a
+                    // fresh one from the current time
+                    let seed = cli_seed.unwrap_or_else(|| {
+                        SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap()
+                            .as_nanos() as u64
+                    });
+
+                    board.randomize(random_density, seed);
+                    generation = 0;
+                    println!(
+                        "Randomized board with seed {} (density {}) - pass these as CLI args to reproduce it",
+                        seed, random_density
+                    );
+                }
+                Key::E => {
+                    rule_index = (rule_index + 1) % RULES.len();
+                    let rule = RULES[rule_index];
+                    board.set_rule(rule).unwrap();
+                    println!("Switched to rule {}", rule);
+                }
+                Key::P => {
+                    board.set_sparse(!board.sparse);
+                    println!(
+                        "Sparse engine {}",
+                        if board.sparse { "enabled" } else { "disabled" }
+                    );
+                }
+                Key::S => match fs::write(RLE_PATH, board.to_rle()) {
+                    Ok(()) => println!("Saved pattern to {}", RLE_PATH),
+                    Err(error) => println!("Failed to save {}: {}", RLE_PATH, error),
+                },
+                Key::L => match fs::read_to_string(RLE_PATH).map(|rle| Board::from_rle(&rle)) {
+                    Ok(Ok(pattern)) => {
+                        board.stamp(&pattern, board_x, board_y);
+                        println!("Loaded pattern from {} at the cursor", RLE_PATH);
+                    }
+                    Ok(Err(error)) => println!("Failed to parse {}: {}", RLE_PATH, error),
+                    Err(error) => println!("Failed to read {}: {}", RLE_PATH, error),
+                },
+                Key::D => match fs::write(PLAINTEXT_PATH, board.to_plaintext()) {
+                    Ok(()) => println!("Saved pattern to {}", PLAINTEXT_PATH),
+                    Err(error) => println!("Failed to save {}: {}", PLAINTEXT_PATH, error),
+                },
+                Key::C => {
+                    match fs::read_to_string(PLAINTEXT_PATH).map(|cells| Board::from_plaintext(&cells))
+                    {
+                        Ok(Ok(pattern)) => {
+                            board.stamp(&pattern, board_x, board_y);
+                            println!("Loaded pattern from {} at the cursor", PLAINTEXT_PATH);
+                        }
+                        Ok(Err(error)) => println!("Failed to parse {}: {}", PLAINTEXT_PATH, error),
+                        Err(error) => println!("Failed to read {}: {}", PLAINTEXT_PATH, error),
+                    }
+                }
                 _ => {}
             }
         }
@@ -81,22 +207,53 @@ fn main() {
 
             board_x = (mouse_x / CELL_SIZE) as i8;
             board_y = (mouse_y / CELL_SIZE) as i8;
+
+            // While dragging, paint a line from the last cell to the new one
+            // so fast mouse movement doesn't leave gaps between frames.
+            // Only counts as a drag once the cursor actually leaves the
+            // cell it started on, so a plain click can still toggle.
+            if left_mouse_down {
+                if let Some((last_x, last_y)) = last_paint_cell {
+                    if (last_x, last_y) != (board_x, board_y) {
+                        drag_moved = true;
+                        for (x, y) in bresenham_line(last_x, last_y, board_x, board_y) {
+                            if painted_this_stroke.insert((x, y)) {
+                                board.set_cell_alive(x, y);
+                            }
+                        }
+                    }
+                }
+                last_paint_cell = Some((board_x, board_y));
+            }
         }
 
-        // If you click it toggles the cell at the mouse position
+        // If you click it starts a potential drag-to-draw stroke at the
+        // mouse position (the cell itself isn't touched until we know
+        // whether this turns into a drag or a plain click)
         if let Some(Button::Mouse(MouseButton::Left)) = event.press_args() {
-            board.player_toggle_cell(board_x, board_y);
+            left_mouse_down = true;
+            drag_moved = false;
+            painted_this_stroke.clear();
+            last_paint_cell = Some((board_x, board_y));
         }
 
-        // Track key release events
-        if let Some(Button::Keyboard(key)) = event.release_args() {
-            pressed_keys.remove(&key);
+        // Releasing the button ends the current stroke. If the cursor never
+        // left its starting cell, treat it as a plain click and toggle that
+        // cell instead, so clicking a live cell can still erase it.
+        if let Some(Button::Mouse(MouseButton::Left)) = event.release_args() {
+            if left_mouse_down && !drag_moved {
+                board.player_toggle_cell(board_x, board_y);
+            }
+
+            left_mouse_down = false;
+            last_paint_cell = None;
+            painted_this_stroke.clear();
+            drag_moved = false;
         }
 
-        // Handle continuous key press actions
-        if pressed_keys.contains(&Key::F) {
-            // Check if enough time has passed since the last auto tick
-            if last_auto_tick.elapsed() >= Duration::from_millis(AUTO_TICK_INTERVAL_MS) {
+        // Auto-advance while running, at the current adjustable interval
+        if sim_state == SimState::Running {
+            if last_auto_tick.elapsed() >= Duration::from_millis(auto_tick_interval_ms) {
                 board.tick();
                 generation += 1;
                 last_auto_tick = Instant::now();
@@ -117,40 +274,69 @@ fn main() {
     }
 }
 
+// Walks a line between two board cells using Bresenham's algorithm, so a
+// drag stroke can fill in every cell between the last and current cursor
+// position instead of leaving gaps on fast mouse movement
+fn bresenham_line(x0: i8, y0: i8, x1: i8, y1: i8) -> Vec<(i8, i8)> {
+    // Use i16 intermediates so the step math can't overflow i8
+    let mut x0 = x0 as i16;
+    let mut y0 = y0 as i16;
+    let x1 = x1 as i16;
+    let y1 = y1 as i16;
+
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx: i16 = if x0 < x1 { 1 } else { -1 };
+    let sy: i16 = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    let mut points = Vec::new();
+
+    loop {
+        points.push((x0 as i8, y0 as i8));
+
+        if x0 == x1 && y0 == y1 {
+            break;
+        }
+
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x0 += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y0 += sy;
+        }
+    }
+
+    points
+}
+
 // Draws the user interface text showing controls (soon to be)
 fn draw_ui(_board: &Board, _generation: u128, _context: &Context) {
     // I will add this later
 }
 
-// Draws the game board with all cells and the player cursor
+// Draws the game board's live cells (the window is already cleared to the
+// dead color before this runs, see `main`)
 fn draw_board<G: Graphics>(board: &Board, cell_size: f64, context: &Context, graphics: &mut G) {
-    // Define colors for different cell states
     let alive_color: [f32; 4] = [0.1, 0.1, 0.1, 1.0]; // RBGA color skema (black)
-    let dead_color: [f32; 4] = [1.0, 1.0, 1.0, 1.0]; // RBGA color skema (white)
 
     // Offset for the board (no offset for now)
     let y_offset: f64 = 0.0;
     let x_offset: f64 = 0.0;
 
-    // Iterate through each cell in the board
-    for y in 0..board.size_y {
-        for x in 0..board.size_x {
-            // Calculate the pixel position of this cell
-            let x_pos = x as f64 * cell_size + x_offset;
-            let y_pos = y as f64 * cell_size + y_offset;
-
-            // Create a rectangle for this cell with 1px gap between cells for visual stuff
-            let cell_rect: [f64; 4] = [x_pos, y_pos, cell_size - 1.0, cell_size - 1.0];
-
-            // Determine the cell's color based on its state (alive or dead)
-            let color: [f32; 4] = if board.board[y as usize][x as usize] == 1 {
-                alive_color
-            } else {
-                dead_color
-            };
-
-            // Draw the cell rectangle
-            rectangle(color, cell_rect, context.transform, graphics);
-        }
+    // Render straight from the live-cell set instead of scanning every cell,
+    // so a sparse, mostly-dead board is cheap to draw too
+    for &(x, y) in board.live_cells() {
+        // Calculate the pixel position of this cell
+        let x_pos = x as f64 * cell_size + x_offset;
+        let y_pos = y as f64 * cell_size + y_offset;
+
+        // Create a rectangle for this cell with 1px gap between cells for visual stuff
+        let cell_rect: [f64; 4] = [x_pos, y_pos, cell_size - 1.0, cell_size - 1.0];
+
+        rectangle(alive_color, cell_rect, context.transform, graphics);
     }
 }
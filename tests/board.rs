@@ -43,3 +43,129 @@ fn test_neighbour_count() {
 
     assert_eq!(board.get_neighbour_count(1, 1), 7);
 }
+
+#[test]
+fn test_wrap_neighbour_count() {
+    let mut board = Board::new(3, 3);
+    board.set_wrap(true);
+
+    // Corners are neighbors of each other when wrapping is enabled
+    board.board[0][0] = 1;
+    board.board[0][2] = 1;
+    board.board[2][0] = 1;
+    board.board[2][2] = 1;
+
+    assert_eq!(board.get_neighbour_count(0, 0), 3);
+}
+
+#[test]
+fn test_randomize_is_reproducible() {
+    let mut board_a = Board::new(10, 10);
+    let mut board_b = Board::new(10, 10);
+
+    board_a.randomize(0.5, 42);
+    board_b.randomize(0.5, 42);
+
+    assert_eq!(board_a.board, board_b.board);
+    assert_eq!(board_a.population, board_b.population);
+}
+
+#[test]
+fn test_set_rule_changes_tick_behaviour() {
+    let mut board = Board::new(3, 3);
+
+    // Under B2/S a lone pair of neighbors is enough to birth a cell
+    board.set_rule("B2/S").unwrap();
+
+    board.board[0][0] = 1;
+    board.board[0][1] = 1;
+    board.population = 2;
+
+    board.tick();
+
+    assert_eq!(board.board[1][0], 1);
+}
+
+#[test]
+fn test_set_rule_rejects_malformed_input() {
+    let mut board = Board::new(3, 3);
+
+    assert!(board.set_rule("nonsense").is_err());
+}
+
+#[test]
+fn test_sparse_tick_matches_dense_tick() {
+    let mut dense = Board::new(5, 5);
+    let mut sparse = Board::new(5, 5);
+    sparse.set_sparse(true);
+
+    // Set up horizontal blinker at row 2 on both boards
+    dense.board[2][1] = 1;
+    dense.board[2][2] = 1;
+    dense.board[2][3] = 1;
+    dense.population = 3;
+
+    for x in 1..=3 {
+        sparse.set_cell_alive(x, 2);
+    }
+
+    dense.tick();
+    sparse.tick();
+
+    assert_eq!(dense.board, sparse.board);
+    assert_eq!(dense.population, sparse.population);
+}
+
+#[test]
+fn test_rle_round_trip() {
+    let mut board = Board::new(5, 5);
+    board.set_cell_alive(1, 2);
+    board.set_cell_alive(2, 2);
+    board.set_cell_alive(3, 2);
+
+    let rle = board.to_rle();
+    let parsed = Board::from_rle(&rle).unwrap();
+
+    assert_eq!(parsed.board, board.board);
+    assert_eq!(parsed.population, board.population);
+}
+
+#[test]
+fn test_plaintext_round_trip() {
+    let mut board = Board::new(5, 5);
+    board.set_cell_alive(1, 2);
+    board.set_cell_alive(2, 2);
+    board.set_cell_alive(3, 2);
+
+    let plaintext = board.to_plaintext();
+    let parsed = Board::from_plaintext(&plaintext).unwrap();
+
+    assert_eq!(parsed.board, board.board);
+    assert_eq!(parsed.population, board.population);
+}
+
+#[test]
+fn test_stamp_centers_pattern_onto_board() {
+    let mut blinker = Board::new(3, 1);
+    blinker.set_cell_alive(0, 0);
+    blinker.set_cell_alive(1, 0);
+    blinker.set_cell_alive(2, 0);
+
+    let mut board = Board::new(5, 5);
+    board.stamp_centered(&blinker);
+
+    assert_eq!(board.board[2][1], 1);
+    assert_eq!(board.board[2][2], 1);
+    assert_eq!(board.board[2][3], 1);
+    assert_eq!(board.population, 3);
+}
+
+#[test]
+fn test_from_rle_rejects_malformed_input() {
+    assert!(Board::from_rle("not a pattern").is_err());
+}
+
+#[test]
+fn test_from_rle_rejects_negative_dimensions() {
+    assert!(Board::from_rle("x = -5, y = -5, rule = B3/S23\no!\n").is_err());
+}